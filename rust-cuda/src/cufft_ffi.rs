@@ -1,3 +1,4 @@
+use crate::cuda_ffi::CudaBuffer;
 use num_complex::Complex32;
 use std::fmt;
 use std::ptr;
@@ -47,6 +48,8 @@ impl std::error::Error for cufftResult {}
 #[derive(Debug, Copy, Clone)]
 pub enum cufftType {
     CUFFT_C2C = 0x29, // Complex-to-Complex
+    CUFFT_R2C = 0x2a, // Real-to-Complex
+    CUFFT_C2R = 0x2c, // Complex-to-Real
 }
 
 // cuFFT transform directions
@@ -76,6 +79,18 @@ extern "C" {
         direction: i32,
     ) -> cufftResult;
 
+    pub fn cufftExecR2C(
+        plan: cufftHandle,
+        idata: *mut f32,
+        odata: *mut Complex32,
+    ) -> cufftResult;
+
+    pub fn cufftExecC2R(
+        plan: cufftHandle,
+        idata: *mut Complex32,
+        odata: *mut f32,
+    ) -> cufftResult;
+
     pub fn cufftDestroy(plan: cufftHandle) -> cufftResult;
 }
 
@@ -114,6 +129,72 @@ impl CufftPlan {
         Ok(Self { handle })
     }
 
+    /// Create a new batch 1D real-to-complex FFT plan
+    ///
+    /// The output is the compact Hermitian-symmetric spectrum, so `odist` is
+    /// `length / 2 + 1` complex elements rather than `length`.
+    pub fn new_batch_1d_r2c(length: usize, batch: usize) -> Result<Self, cufftResult> {
+        let mut handle = cufftHandle(0);
+        let n = [length as i32];
+        let odist = (length / 2 + 1) as i32;
+        let onembed = [odist];
+
+        unsafe {
+            let result = cufftPlanMany(
+                &mut handle,
+                1,                      // rank (1D)
+                n.as_ptr(),            // dimensions of each FFT
+                ptr::null(),           // inembed (NULL = same as n)
+                1,                     // istride (elements are contiguous)
+                length as i32,         // idist (distance between real signals)
+                onembed.as_ptr(),      // onembed (Hermitian spectrum length)
+                1,                     // ostride
+                odist,                 // odist (distance between complex spectra)
+                cufftType::CUFFT_R2C, // type (real-to-complex)
+                batch as i32,          // batch size
+            );
+
+            if result != cufftResult::CUFFT_SUCCESS {
+                return Err(result);
+            }
+        }
+
+        Ok(Self { handle })
+    }
+
+    /// Create a new batch 1D complex-to-real FFT plan
+    ///
+    /// The input is the compact Hermitian-symmetric spectrum, so `idist` is
+    /// `length / 2 + 1` complex elements rather than `length`.
+    pub fn new_batch_1d_c2r(length: usize, batch: usize) -> Result<Self, cufftResult> {
+        let mut handle = cufftHandle(0);
+        let n = [length as i32];
+        let idist = (length / 2 + 1) as i32;
+        let inembed = [idist];
+
+        unsafe {
+            let result = cufftPlanMany(
+                &mut handle,
+                1,                      // rank (1D)
+                n.as_ptr(),            // dimensions of each FFT
+                inembed.as_ptr(),      // inembed (Hermitian spectrum length)
+                1,                     // istride (elements are contiguous)
+                idist,                 // idist (distance between complex spectra)
+                ptr::null(),           // onembed (NULL = same as n)
+                1,                     // ostride
+                length as i32,         // odist (distance between real signals)
+                cufftType::CUFFT_C2R, // type (complex-to-real)
+                batch as i32,          // batch size
+            );
+
+            if result != cufftResult::CUFFT_SUCCESS {
+                return Err(result);
+            }
+        }
+
+        Ok(Self { handle })
+    }
+
     /// Execute forward FFT in-place
     pub fn execute_forward(&self, data: *mut Complex32) -> Result<(), String> {
         unsafe {
@@ -130,6 +211,44 @@ impl CufftPlan {
 
         Ok(())
     }
+
+    /// Execute a real-to-complex forward FFT, writing the Hermitian spectrum to `output`
+    pub fn execute_r2c(
+        &self,
+        input: &CudaBuffer<f32>,
+        output: &mut CudaBuffer<Complex32>,
+    ) -> Result<(), String> {
+        unsafe {
+            let result = cufftExecR2C(self.handle, input.as_ptr(), output.as_ptr());
+            if result != cufftResult::CUFFT_SUCCESS {
+                return Err(format!("cuFFT execution failed: {}", result));
+            }
+        }
+
+        crate::cuda_ffi::cuda_device_synchronize()
+            .map_err(|e| format!("CUDA synchronization failed: {}", e))?;
+
+        Ok(())
+    }
+
+    /// Execute a complex-to-real inverse FFT, writing the real signal to `output`
+    pub fn execute_c2r(
+        &self,
+        input: &CudaBuffer<Complex32>,
+        output: &mut CudaBuffer<f32>,
+    ) -> Result<(), String> {
+        unsafe {
+            let result = cufftExecC2R(self.handle, input.as_ptr(), output.as_ptr());
+            if result != cufftResult::CUFFT_SUCCESS {
+                return Err(format!("cuFFT execution failed: {}", result));
+            }
+        }
+
+        crate::cuda_ffi::cuda_device_synchronize()
+            .map_err(|e| format!("CUDA synchronization failed: {}", e))?;
+
+        Ok(())
+    }
 }
 
 impl Drop for CufftPlan {